@@ -3,11 +3,136 @@ use crate::{
     layout::Rect,
     style::Style,
     symbols,
+    text::Spans,
     widgets::{Block, Widget},
 };
+use smallvec::SmallVec;
 use std::cmp::min;
 use unicode_width::UnicodeWidthStr;
 
+/// Horizontal 9-level symbol set, used to fill a bar growing rightward one eighth of a cell at a
+/// time. Unlike [`symbols::bar::Set`], which grows a cell from the bottom up, these symbols grow
+/// a cell from the left.
+const HORIZONTAL_BAR_SYMBOLS: [&str; 9] = [" ", "▏", "▎", "▍", "▌", "▋", "▊", "▉", "█"];
+
+/// Inline capacity for the per-frame scaled-height scratch buffer: wide enough for most
+/// terminals, falling back to the heap only for unusually wide ones.
+const INLINE_BARS: usize = 128;
+
+/// Orientation of a [`BarChart`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Bars grow from the bottom of the chart upward (the default).
+    Vertical,
+    /// Bars grow from the left of the chart rightward.
+    Horizontal,
+}
+
+impl Default for Direction {
+    fn default() -> Self {
+        Self::Vertical
+    }
+}
+
+/// A single bar inside a [`BarGroup`], to be shown by the [`BarChart`] widget.
+///
+/// # Examples
+///
+/// The following example creates a bar with the label "Bar 1", a value 2, a red foreground style
+/// for the bar and a red background style for its value.
+/// ```
+/// # use tui::widgets::Bar;
+/// # use tui::style::{Style, Color};
+/// Bar::default()
+///     .label("Bar 1".into())
+///     .value(2)
+///     .style(Style::default().fg(Color::Red))
+///     .value_style(Style::default().bg(Color::Red));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Bar<'a> {
+    /// Value of the bar, scaled against the chart's maximum to size the bar
+    pub value: u64,
+    /// Optional label to be printed under the bar (to its left in a horizontal chart)
+    pub label: Option<Spans<'a>>,
+    /// Optional text printed instead of the numeric value. A vertical chart only draws it when it
+    /// is narrower than the bar; a horizontal chart clips it at the chart's right edge.
+    pub text_value: Option<String>,
+    /// Style for the bar
+    pub style: Option<Style>,
+    /// Style of the value printed at the bottom of the bar (at its end in a horizontal chart)
+    pub value_style: Option<Style>,
+}
+
+impl<'a> Bar<'a> {
+    pub fn value(mut self, value: u64) -> Self {
+        self.value = value;
+        self
+    }
+
+    pub fn label(mut self, label: Spans<'a>) -> Self {
+        self.label = Some(label);
+        self
+    }
+
+    pub fn text_value(mut self, text_value: String) -> Self {
+        self.text_value = Some(text_value);
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    pub fn value_style(mut self, style: Style) -> Self {
+        self.value_style = Some(style);
+        self
+    }
+}
+
+/// A group of [`Bar`], to be shown by the [`BarChart`] widget.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::{Bar, BarGroup};
+/// BarGroup::default()
+///     .label("Group 1".into())
+///     .bars(&[Bar::default().value(2), Bar::default().value(5)]);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct BarGroup<'a> {
+    /// Optional label to be printed under the group of bars
+    pub label: Option<Spans<'a>>,
+    /// Bars of the group
+    pub bars: Vec<Bar<'a>>,
+}
+
+impl<'a> BarGroup<'a> {
+    pub fn label(mut self, label: Spans<'a>) -> Self {
+        self.label = Some(label);
+        self
+    }
+
+    pub fn bars(mut self, bars: &[Bar<'a>]) -> Self {
+        self.bars = bars.to_vec();
+        self
+    }
+}
+
+impl<'a> From<&[(&'a str, u64)]> for BarGroup<'a> {
+    fn from(data: &[(&'a str, u64)]) -> Self {
+        Self {
+            label: None,
+            bars: data
+                .iter()
+                .map(|(label, value)| Bar::default().label(Spans::from(*label)).value(*value))
+                .collect(),
+        }
+    }
+}
+
 /// Display multiple bars in a single widgets
 ///
 /// # Examples
@@ -33,6 +158,8 @@ pub struct BarChart<'a> {
     pub bar_width: u16,
     /// The gap between each bar
     pub bar_gap: u16,
+    /// The gap between each group
+    pub group_gap: u16,
     /// Set of symbols used to display the data
     pub bar_set: symbols::bar::Set,
     /// Style of the bars
@@ -43,14 +170,13 @@ pub struct BarChart<'a> {
     pub label_style: Style,
     /// Style for the widget
     pub style: Style,
-    /// Slice of (label, value) pair to plot on the chart.
-    /// Cannot be modified directly, only with `set_data()`.
-    data: &'a [(&'a str, u64)],
+    /// Groups of bars to be shown. Cannot be modified directly, only with `data()`.
+    data: Vec<BarGroup<'a>>,
     /// Value necessary for a bar to reach the maximum height (if no value is specified,
     /// the maximum value in the data is taken as reference)
     pub max: Option<u64>,
-    /// Values to display on the bar (computed when the data is passed to the widget)
-    pub values: Vec<String>,
+    /// Orientation of the chart
+    pub direction: Direction,
 }
 
 impl<'a> Default for BarChart<'a> {
@@ -58,31 +184,30 @@ impl<'a> Default for BarChart<'a> {
         Self {
             block: None,
             max: None,
-            data: &[],
-            values: Vec::new(),
+            data: Vec::new(),
             bar_style: Style::default(),
             bar_width: 1,
             bar_gap: 1,
+            group_gap: 0,
             bar_set: symbols::bar::NINE_LEVELS,
             value_style: Default::default(),
             label_style: Default::default(),
             style: Default::default(),
+            direction: Direction::Vertical,
         }
     }
 }
 
 impl<'a> BarChart<'a> {
-    pub fn data(mut self, data: &'a [(&'a str, u64)]) -> Self {
-        self.set_data(data);
-        self
-    }
-
-    pub fn set_data(&mut self, data: &'a [(&'a str, u64)]) {
-        self.data = data;
-        self.values = Vec::with_capacity(self.data.len());
-        for &(_, v) in self.data {
-            self.values.push(format!("{}", v));
+    /// Add a group of bars to the chart. May be called several times to append more groups to
+    /// the same chart. The tuple-slice `&[(&str, u64)]` API is kept working by converting into a
+    /// single unlabeled group.
+    pub fn data(mut self, data: impl Into<BarGroup<'a>>) -> Self {
+        let group: BarGroup<'a> = data.into();
+        if !group.bars.is_empty() {
+            self.data.push(group);
         }
+        self
     }
 
     pub fn block(mut self, block: Block<'a>) -> Self {
@@ -110,6 +235,11 @@ impl<'a> BarChart<'a> {
         self
     }
 
+    pub fn group_gap(mut self, gap: u16) -> Self {
+        self.group_gap = gap;
+        self
+    }
+
     pub fn bar_set(mut self, bar_set: symbols::bar::Set) -> Self {
         self.bar_set = bar_set;
         self
@@ -129,6 +259,72 @@ impl<'a> BarChart<'a> {
         self.style = style;
         self
     }
+
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+}
+
+/// A bar placed at a given position along the chart's main axis (a column for a vertical chart,
+/// a row for a horizontal one).
+struct PlacedBar<'b, 'a> {
+    pos: u16,
+    bar: &'b Bar<'a>,
+}
+
+impl<'a> BarChart<'a> {
+    /// Lay out every group as a run of adjacent bars along the main axis, separated from the
+    /// next group by `group_gap`, stopping as soon as a bar would overflow `[start, end)`.
+    ///
+    /// When `reserve_group_label_row` is set, a labeled group has its first main-axis unit
+    /// carved out for the group label alone, so it gets a row/column of its own instead of
+    /// sharing space with the bars or their own labels (used by the horizontal orientation,
+    /// where the bar-label row for every bar in the group would otherwise be drawn right over
+    /// it). The vertical orientation instead draws the group label on a dedicated trailing row
+    /// below the bars, so it passes `false` and every main-axis unit is used for bars.
+    fn place_bars<'b>(
+        &'b self,
+        start: u16,
+        end: u16,
+        reserve_group_label_row: bool,
+    ) -> (Vec<PlacedBar<'b, 'a>>, Vec<(u16, u16, &'b Spans<'a>)>) {
+        let mut bars = Vec::new();
+        let mut group_labels = Vec::new();
+        let mut pos = start;
+        'groups: for group in &self.data {
+            if group.bars.is_empty() {
+                continue;
+            }
+            let reserve_label = reserve_group_label_row && group.label.is_some();
+            let label_row = pos;
+            if reserve_label && pos + 1 + self.bar_width > end {
+                break;
+            }
+            if reserve_label {
+                group_labels.push((label_row, 1, group.label.as_ref().unwrap()));
+                pos += 1;
+            }
+            let group_start = pos;
+            for (i, bar) in group.bars.iter().enumerate() {
+                if pos + self.bar_width > end {
+                    break 'groups;
+                }
+                bars.push(PlacedBar { pos, bar });
+                pos += self.bar_width;
+                if i + 1 != group.bars.len() {
+                    pos += self.bar_gap;
+                }
+            }
+            if !reserve_group_label_row {
+                if let Some(label) = &group.label {
+                    group_labels.push((group_start, pos - group_start, label));
+                }
+            }
+            pos += self.group_gap;
+        }
+        (bars, group_labels)
+    }
 }
 
 impl<'a> Widget for BarChart<'a> {
@@ -148,67 +344,243 @@ impl<'a> Widget for BarChart<'a> {
             return;
         }
 
-        let max = self
-            .max
-            .unwrap_or_else(|| self.data.iter().map(|t| t.1).max().unwrap_or_default());
-        let max_index = min(
-            (chart_area.width / (self.bar_width + self.bar_gap)) as usize,
-            self.data.len(),
-        );
-        let mut data = self
-            .data
+        let max = self.max.unwrap_or_else(|| {
+            self.data
+                .iter()
+                .flat_map(|group| group.bars.iter())
+                .map(|bar| bar.value)
+                .max()
+                .unwrap_or_default()
+        });
+
+        match self.direction {
+            Direction::Vertical => self.render_vertical(chart_area, buf, max),
+            Direction::Horizontal => self.render_horizontal(chart_area, buf, max),
+        }
+    }
+}
+
+impl<'a> BarChart<'a> {
+    fn render_vertical(&self, chart_area: Rect, buf: &mut Buffer, max: u64) {
+        let (bars, group_labels) = self.place_bars(chart_area.left(), chart_area.right(), false);
+
+        // A group label needs its own row below the bar-label row, so only reserve (and later
+        // draw) it if there's room for all three rows: bars, bar labels, group labels.
+        let show_group_labels = !group_labels.is_empty() && chart_area.height >= 3;
+        let group_label_height = u16::from(show_group_labels);
+        let bars_area_height = chart_area
+            .height
+            .saturating_sub(1 + group_label_height)
+            .max(1);
+
+        let mut data: SmallVec<[u64; INLINE_BARS]> = bars
             .iter()
-            .take(max_index)
-            .map(|&(l, v)| {
-                (
-                    l,
-                    v * u64::from(chart_area.height - 1) * 8 / std::cmp::max(max, 1),
-                )
-            })
-            .collect::<Vec<(&str, u64)>>();
-        for j in (0..chart_area.height - 1).rev() {
+            .map(|p| p.bar.value * u64::from(bars_area_height) * 8 / std::cmp::max(max, 1))
+            .collect();
+        for j in (0..bars_area_height).rev() {
             for (i, d) in data.iter_mut().enumerate() {
-                let symbol = self.bar_set.symbol(d.1 as usize);
-
-                for x in 0..self.bar_width {
-                    buf.get_mut(
-                        chart_area.left() + i as u16 * (self.bar_width + self.bar_gap) + x,
-                        chart_area.top() + j,
-                    )
-                    .set_symbol(symbol)
-                    .set_style(self.bar_style);
+                let symbol = self.bar_set.symbol(*d as usize);
+                let style = bars[i].bar.style.unwrap_or(self.bar_style);
+                for k in 0..self.bar_width {
+                    buf.get_mut(bars[i].pos + k, chart_area.top() + j)
+                        .set_symbol(symbol)
+                        .set_style(style);
                 }
 
-                if d.1 > 8 {
-                    d.1 -= 8;
+                if *d > 8 {
+                    *d -= 8;
                 } else {
-                    d.1 = 0;
+                    *d = 0;
                 }
             }
         }
 
-        for (i, &(label, value)) in self.data.iter().take(max_index).enumerate() {
-            if value != 0 {
-                let value_label = &self.values[i];
+        let bar_label_y = chart_area.top() + bars_area_height;
+        let mut itoa_buf = itoa::Buffer::new();
+        for placed in &bars {
+            if placed.bar.value != 0 {
+                let value_label: &str = match &placed.bar.text_value {
+                    Some(text) => text.as_str(),
+                    None => itoa_buf.format(placed.bar.value),
+                };
                 let width = value_label.width() as u16;
                 if width < self.bar_width {
+                    let value_style = placed.bar.value_style.unwrap_or(self.value_style);
                     buf.set_string(
-                        chart_area.left()
-                            + i as u16 * (self.bar_width + self.bar_gap)
-                            + (self.bar_width - width) / 2,
-                        chart_area.bottom() - 2,
+                        placed.pos + (self.bar_width - width) / 2,
+                        bar_label_y - 1,
                         value_label,
-                        self.value_style,
+                        value_style,
                     );
                 }
             }
-            buf.set_stringn(
-                chart_area.left() + i as u16 * (self.bar_width + self.bar_gap),
-                chart_area.bottom() - 1,
-                label,
-                self.bar_width as usize,
-                self.label_style,
+            if let Some(label) = &placed.bar.label {
+                buf.set_spans(placed.pos, bar_label_y, label, self.bar_width);
+            }
+        }
+
+        if show_group_labels {
+            let group_label_y = bar_label_y + 1;
+            for (x, width, label) in group_labels {
+                let label_width = label.width() as u16;
+                let x = if label_width < width {
+                    x + (width - label_width) / 2
+                } else {
+                    x
+                };
+                buf.set_spans(x, group_label_y, label, width);
+            }
+        }
+    }
+
+    fn render_horizontal(&self, chart_area: Rect, buf: &mut Buffer, max: u64) {
+        let label_width = self
+            .data
+            .iter()
+            .flat_map(|group| group.bars.iter())
+            .filter_map(|bar| bar.label.as_ref())
+            .map(|label| label.width() as u16)
+            .max()
+            .unwrap_or(0)
+            .min(chart_area.width.saturating_sub(1));
+        let bars_left = chart_area.left() + label_width + 1;
+        let bars_width = chart_area.right().saturating_sub(bars_left);
+        if bars_width == 0 {
+            return;
+        }
+
+        // Each labeled group gets its own reserved row above its bars (see `place_bars`), so
+        // drawing it here never competes with a bar's fill or per-bar label for the same row.
+        let (bars, group_labels) = self.place_bars(chart_area.top(), chart_area.bottom(), true);
+        for (row, _, label) in &group_labels {
+            buf.set_spans(chart_area.left(), *row, label, chart_area.width);
+        }
+
+        let mut itoa_buf = itoa::Buffer::new();
+        for placed in &bars {
+            let scaled = placed.bar.value * u64::from(bars_width) * 8 / std::cmp::max(max, 1);
+            let full_cells = min((scaled / 8) as u16, bars_width);
+            let remainder = (scaled % 8) as usize;
+            let style = placed.bar.style.unwrap_or(self.bar_style);
+            for row in 0..self.bar_width {
+                let y = placed.pos + row;
+                for col in 0..full_cells {
+                    buf.get_mut(bars_left + col, y)
+                        .set_symbol(HORIZONTAL_BAR_SYMBOLS[8])
+                        .set_style(style);
+                }
+                if full_cells < bars_width {
+                    buf.get_mut(bars_left + full_cells, y)
+                        .set_symbol(HORIZONTAL_BAR_SYMBOLS[remainder])
+                        .set_style(style);
+                }
+            }
+
+            if let Some(label) = &placed.bar.label {
+                let label_row = placed.pos + self.bar_width / 2;
+                let width = label.width() as u16;
+                let x = chart_area.left() + label_width.saturating_sub(width);
+                buf.set_spans(x, label_row, label, label_width);
+            }
+
+            if placed.bar.value != 0 {
+                let value_label: &str = match &placed.bar.text_value {
+                    Some(text) => text.as_str(),
+                    None => itoa_buf.format(placed.bar.value),
+                };
+                let value_style = placed.bar.value_style.unwrap_or(self.value_style);
+                let value_row = placed.pos + self.bar_width / 2;
+                let value_x = (bars_left + full_cells).min(chart_area.right().saturating_sub(1));
+                buf.set_stringn(
+                    value_x,
+                    value_row,
+                    value_label,
+                    chart_area.right().saturating_sub(value_x) as usize,
+                    value_style,
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::Buffer;
+
+    #[test]
+    fn bar_group_from_tuple_slice_converts_into_unlabeled_group() {
+        let group: BarGroup = [("B0", 0), ("B1", 2)][..].into();
+        assert_eq!(group.label, None);
+        assert_eq!(group.bars.len(), 2);
+        assert_eq!(group.bars[0].value, 0);
+        assert_eq!(group.bars[1].value, 2);
+        assert_eq!(group.bars[1].label, Some(Spans::from("B1")));
+    }
+
+    #[test]
+    fn data_skips_empty_groups() {
+        let chart = BarChart::default()
+            .data(BarGroup::default().label("empty".into()))
+            .data(&[("B0", 1)][..]);
+        assert_eq!(chart.data.len(), 1);
+    }
+
+    #[test]
+    fn it_does_not_panic_if_height_is_two_with_a_labeled_group_vertical() {
+        let mut widget = BarChart::default().data(
+            BarGroup::default()
+                .label("Group".into())
+                .bars(&[Bar::default().value(1).label("B0".into())]),
+        );
+        let area = Rect::new(0, 0, 10, 2);
+        let mut buffer = Buffer::empty(area);
+        widget.render(area, &mut buffer);
+    }
+
+    #[test]
+    fn it_does_not_panic_if_height_is_two_with_a_labeled_group_horizontal() {
+        let mut widget = BarChart::default()
+            .direction(Direction::Horizontal)
+            .data(
+                BarGroup::default()
+                    .label("Group".into())
+                    .bars(&[Bar::default().value(1).label("B0".into())]),
+            );
+        let area = Rect::new(0, 0, 10, 2);
+        let mut buffer = Buffer::empty(area);
+        widget.render(area, &mut buffer);
+    }
+
+    #[test]
+    fn it_renders_horizontal_bars_without_overwriting_the_group_label() {
+        let mut widget = BarChart::default()
+            .direction(Direction::Horizontal)
+            .bar_width(1)
+            .data(
+                BarGroup::default()
+                    .label("Group".into())
+                    .bars(&[Bar::default().value(4), Bar::default().value(8)]),
             );
+        let area = Rect::new(0, 0, 10, 5);
+        let mut buffer = Buffer::empty(area);
+        widget.render(area, &mut buffer);
+
+        let group_label_row = area.top();
+        let content: String = (area.left()..area.right())
+            .map(|x| buffer.get(x, group_label_row).symbol.clone())
+            .collect();
+        assert!(content.starts_with("Group"));
+    }
+
+    #[test]
+    fn it_does_not_panic_with_more_bars_than_the_inline_scratch_capacity() {
+        let mut chart = BarChart::default();
+        for i in 0..(INLINE_BARS as u64 + 10) {
+            chart = chart.data(BarGroup::from(&[("B", i)][..]));
         }
+        let area = Rect::new(0, 0, (INLINE_BARS as u16 + 10) * 2, 5);
+        let mut buffer = Buffer::empty(area);
+        chart.render(area, &mut buffer);
     }
 }
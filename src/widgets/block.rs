@@ -1,12 +1,141 @@
 use crate::{
     buffer::Buffer,
-    layout::Rect,
+    layout::{Alignment, Rect},
     style::Style,
     symbols::line,
     text::{Span, Spans},
     widgets::{Borders, Widget},
 };
 
+/// Vertical position of a [`Title`] within a [`Block`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Position {
+    /// Place the title on the top border.
+    Top,
+    /// Place the title on the bottom border.
+    Bottom,
+}
+
+impl Default for Position {
+    fn default() -> Self {
+        Self::Top
+    }
+}
+
+/// A title for a [`Block`], with a position and an alignment.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::block::{Title, Position};
+/// # use tui::layout::Alignment;
+/// Title::from("Title")
+///     .position(Position::Bottom)
+///     .alignment(Alignment::Right);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Title<'a> {
+    /// Content of the title
+    pub content: Spans<'a>,
+    /// Position of the title relative to the block
+    pub position: Position,
+    /// Alignment of the title within its position
+    pub alignment: Alignment,
+}
+
+impl<'a> Title<'a> {
+    pub fn content<T>(mut self, content: T) -> Self
+    where
+        T: Into<Spans<'a>>,
+    {
+        self.content = content.into();
+        self
+    }
+
+    pub fn position(mut self, position: Position) -> Self {
+        self.position = position;
+        self
+    }
+
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+}
+
+impl<'a, T> From<T> for Title<'a>
+where
+    T: Into<Spans<'a>>,
+{
+    fn from(content: T) -> Title<'a> {
+        Title {
+            content: content.into(),
+            position: Position::Top,
+            alignment: Alignment::Left,
+        }
+    }
+}
+
+/// Interior spacing reserved between a [`Block`]'s border/title and its inner area.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::block::Padding;
+/// Padding::uniform(1);
+/// Padding::horizontal(2);
+/// Padding::new(1, 1, 2, 2);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Padding {
+    pub left: u16,
+    pub right: u16,
+    pub top: u16,
+    pub bottom: u16,
+}
+
+impl Padding {
+    pub fn new(left: u16, right: u16, top: u16, bottom: u16) -> Self {
+        Padding {
+            left,
+            right,
+            top,
+            bottom,
+        }
+    }
+
+    pub fn zero() -> Self {
+        Padding::default()
+    }
+
+    pub fn uniform(value: u16) -> Self {
+        Padding {
+            left: value,
+            right: value,
+            top: value,
+            bottom: value,
+        }
+    }
+
+    pub fn horizontal(value: u16) -> Self {
+        Padding {
+            left: value,
+            right: value,
+            top: 0,
+            bottom: 0,
+        }
+    }
+
+    pub fn vertical(value: u16) -> Self {
+        Padding {
+            left: 0,
+            right: 0,
+            top: value,
+            bottom: value,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum BorderType {
     Plain,
@@ -49,9 +178,9 @@ impl Default for BorderType {
 /// ```
 #[derive(Debug, Default, Clone, PartialEq)]
 pub struct Block<'a> {
-    /// Optional title place on the upper left of the block.
+    /// Titles placed around the block, each with its own position and alignment.
     /// Cannot be modified directly, only with `retitle()` and `untitle()`.
-    title: Option<Spans<'a>>,
+    titles: Vec<Title<'a>>,
     /// Visible borders
     pub borders: Borders,
     /// Border style
@@ -61,26 +190,31 @@ pub struct Block<'a> {
     pub border_type: BorderType,
     /// Widget style
     pub style: Style,
+    /// Interior padding, applied after borders and titles when computing the inner area
+    pub padding: Padding,
 }
 
 impl<'a> Block<'a> {
+    /// Add a title to the block. May be called multiple times to add several titles; each is
+    /// placed according to its own [`Position`] and [`Alignment`] (both default to top-left, so
+    /// this keeps working exactly like the old single-title API).
     pub fn title<T>(mut self, title: T) -> Self
     where
-        T: Into<Spans<'a>>,
+        T: Into<Title<'a>>,
     {
-        self.title = Some(title.into());
+        self.titles.push(title.into());
         self
     }
 
     pub fn retitle<T>(&mut self, title: T)
     where
-        T: Into<Spans<'a>>,
+        T: Into<Title<'a>>,
     {
-        self.title = Some(title.into());
+        self.titles = vec![title.into()];
     }
 
     pub fn untitle(&mut self) {
-        self.title = None;
+        self.titles.clear();
     }
 
     #[deprecated(
@@ -88,9 +222,9 @@ impl<'a> Block<'a> {
         note = "You should use styling capabilities of `text::Spans` given as argument of the `title` method to apply styling to the title."
     )]
     pub fn title_style(mut self, style: Style) -> Self {
-        if let Some(t) = self.title {
-            let title = String::from(t);
-            self.title = Some(Spans::from(Span::styled(title, style)));
+        if let Some(t) = self.titles.last_mut() {
+            let content = String::from(t.content.clone());
+            t.content = Spans::from(Span::styled(content, style));
         }
         self
     }
@@ -119,6 +253,16 @@ impl<'a> Block<'a> {
         self
     }
 
+    pub fn padding(mut self, padding: Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Whether any title is anchored to the given `position`.
+    fn has_title(&self, position: Position) -> bool {
+        self.titles.iter().any(|title| title.position == position)
+    }
+
     /// Compute the inner area of a block based on its border visibility rules.
     pub fn inner(&self, area: Rect) -> Rect {
         let mut inner = area;
@@ -126,16 +270,25 @@ impl<'a> Block<'a> {
             inner.x = inner.x.saturating_add(1).min(inner.right());
             inner.width = inner.width.saturating_sub(1);
         }
-        if self.borders.intersects(Borders::TOP) || self.title.is_some() {
+        if self.borders.intersects(Borders::TOP) || self.has_title(Position::Top) {
             inner.y = inner.y.saturating_add(1).min(inner.bottom());
             inner.height = inner.height.saturating_sub(1);
         }
         if self.borders.intersects(Borders::RIGHT) {
             inner.width = inner.width.saturating_sub(1);
         }
-        if self.borders.intersects(Borders::BOTTOM) {
+        if self.borders.intersects(Borders::BOTTOM) || self.has_title(Position::Bottom) {
             inner.height = inner.height.saturating_sub(1);
         }
+
+        inner.x = inner.x.saturating_add(self.padding.left).min(inner.right());
+        inner.y = inner.y.saturating_add(self.padding.top).min(inner.bottom());
+        inner.width = inner
+            .width
+            .saturating_sub(self.padding.left.saturating_add(self.padding.right));
+        inner.height = inner
+            .height
+            .saturating_sub(self.padding.top.saturating_add(self.padding.bottom));
         inner
     }
 }
@@ -202,19 +355,93 @@ impl<'a> Widget for Block<'a> {
                 .set_style(self.border_style);
         }
 
-        if let Some(title) = &self.title {
-            let lx = if self.borders.intersects(Borders::LEFT) {
-                1
-            } else {
-                0
-            };
-            let rx = if self.borders.intersects(Borders::RIGHT) {
-                1
-            } else {
-                0
-            };
-            let width = area.width.saturating_sub(lx).saturating_sub(rx);
-            buf.set_spans(area.left() + lx, area.top(), &title, width);
+        self.render_titles(Position::Top, area, buf);
+        self.render_titles(Position::Bottom, area, buf);
+    }
+}
+
+impl<'a> Block<'a> {
+    /// Render every title anchored to `position`, grouped by [`Alignment`]: left titles packed
+    /// from the left edge, right titles packed against the right edge, and center titles
+    /// centered in whatever span is left between them.
+    fn render_titles(&self, position: Position, area: Rect, buf: &mut Buffer) {
+        if area.area() == 0 {
+            return;
+        }
+        let y = match position {
+            Position::Top => area.top(),
+            Position::Bottom => {
+                if area.bottom() <= area.top() {
+                    return;
+                }
+                area.bottom() - 1
+            }
+        };
+        let lx = if self.borders.intersects(Borders::LEFT) {
+            1
+        } else {
+            0
+        };
+        let rx = if self.borders.intersects(Borders::RIGHT) {
+            1
+        } else {
+            0
+        };
+        let left = area.left() + lx;
+        let right = area.right().saturating_sub(rx);
+        if right <= left {
+            return;
+        }
+
+        let titles: Vec<&Title> = self
+            .titles
+            .iter()
+            .filter(|title| title.position == position)
+            .collect();
+
+        let mut cursor = left;
+        for title in titles.iter().filter(|t| t.alignment == Alignment::Left) {
+            let width = (right - cursor).min(title.content.width() as u16);
+            buf.set_spans(cursor, y, &title.content, width);
+            cursor = (cursor + width + 1).min(right);
+        }
+        let lefts_end = cursor;
+
+        let rights: Vec<&Title> = titles
+            .iter()
+            .filter(|t| t.alignment == Alignment::Right)
+            .copied()
+            .collect();
+        let rights_width: u16 = rights
+            .iter()
+            .map(|t| t.content.width() as u16 + 1)
+            .sum::<u16>()
+            .saturating_sub(1);
+        let mut cursor = right.saturating_sub(rights_width.min(right - left));
+        let rights_start = cursor;
+        for title in rights {
+            let width = (right - cursor).min(title.content.width() as u16);
+            buf.set_spans(cursor, y, &title.content, width);
+            cursor = (cursor + width + 1).min(right);
+        }
+
+        let centers: Vec<&Title> = titles
+            .iter()
+            .filter(|t| t.alignment == Alignment::Center)
+            .copied()
+            .collect();
+        let centers_width: u16 = centers
+            .iter()
+            .map(|t| t.content.width() as u16 + 1)
+            .sum::<u16>()
+            .saturating_sub(1);
+        let span_start = lefts_end;
+        let span_end = rights_start.max(span_start);
+        let mut cursor = span_start + (span_end - span_start).saturating_sub(centers_width) / 2;
+        for title in centers {
+            let width = (right - cursor).min(title.content.width() as u16);
+            buf.set_spans(cursor, y, &title.content, width);
+            cursor = (cursor + width + 1).min(right);
         }
     }
 }
@@ -518,4 +745,80 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn inner_takes_into_account_the_padding() {
+        // Padding alone, no borders
+        assert_eq!(
+            Block::default().padding(Padding::uniform(1)).inner(Rect {
+                x: 0,
+                y: 0,
+                width: 4,
+                height: 4,
+            }),
+            Rect {
+                x: 1,
+                y: 1,
+                width: 2,
+                height: 2,
+            },
+            "padding, no borders"
+        );
+
+        // Padding combined with borders
+        assert_eq!(
+            Block::default()
+                .borders(Borders::ALL)
+                .padding(Padding::uniform(1))
+                .inner(Rect {
+                    x: 0,
+                    y: 0,
+                    width: 4,
+                    height: 4,
+                }),
+            Rect {
+                x: 2,
+                y: 2,
+                width: 0,
+                height: 0,
+            },
+            "padding, all borders"
+        );
+
+        // Asymmetric padding
+        assert_eq!(
+            Block::default()
+                .padding(Padding::new(1, 0, 2, 0))
+                .inner(Rect {
+                    x: 0,
+                    y: 0,
+                    width: 4,
+                    height: 4,
+                }),
+            Rect {
+                x: 1,
+                y: 2,
+                width: 3,
+                height: 2,
+            },
+            "asymmetric padding"
+        );
+
+        // Padding that exceeds the available area saturates to a zero-size rect
+        assert_eq!(
+            Block::default().padding(Padding::uniform(2)).inner(Rect {
+                x: 0,
+                y: 0,
+                width: 2,
+                height: 2,
+            }),
+            Rect {
+                x: 2,
+                y: 2,
+                width: 0,
+                height: 0,
+            },
+            "padding larger than area"
+        );
+    }
 }
@@ -5,8 +5,61 @@ use crate::{
     symbols,
     widgets::{Block, Widget},
 };
+use smallvec::SmallVec;
 use std::cmp::min;
 
+/// Inline capacity for the per-frame scaled-sample scratch buffer: wide enough for most
+/// terminals, falling back to the heap only for unusually wide ones.
+const INLINE_SAMPLES: usize = 128;
+
+/// Direction in which a [`Sparkline`]'s data is laid out and drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderDirection {
+    /// The first sample is drawn at the left edge, newer samples grow to the right.
+    LeftToRight,
+    /// The last sample is anchored to the right edge, older samples grow to the left. Useful for
+    /// streaming data that should scroll leftward as new samples arrive.
+    RightToLeft,
+}
+
+impl Default for RenderDirection {
+    fn default() -> Self {
+        Self::LeftToRight
+    }
+}
+
+/// Converts user-supplied samples into the sparkline's internal `Option<u64>` representation.
+///
+/// This lets [`Sparkline::data`] keep accepting a plain `&[u64]` (every sample present) as well
+/// as a `&[Option<u64>]` (gaps represented as `None`).
+pub trait IntoSparklineData {
+    fn into_sparkline_data(self) -> Vec<Option<u64>>;
+}
+
+impl IntoSparklineData for &[u64] {
+    fn into_sparkline_data(self) -> Vec<Option<u64>> {
+        self.iter().map(|v| Some(*v)).collect()
+    }
+}
+
+impl IntoSparklineData for &[Option<u64>] {
+    fn into_sparkline_data(self) -> Vec<Option<u64>> {
+        self.to_vec()
+    }
+}
+
+impl<const N: usize> IntoSparklineData for &[u64; N] {
+    fn into_sparkline_data(self) -> Vec<Option<u64>> {
+        self.iter().map(|v| Some(*v)).collect()
+    }
+}
+
+impl<const N: usize> IntoSparklineData for &[Option<u64>; N] {
+    fn into_sparkline_data(self) -> Vec<Option<u64>> {
+        self.to_vec()
+    }
+}
+
 /// Widget to render a sparkline over one or more lines.
 ///
 /// # Examples
@@ -26,13 +79,23 @@ pub struct Sparkline<'a> {
     pub block: Option<Block<'a>>,
     /// Widget style
     pub style: Style,
-    /// A slice of the data to display
-    pub data: &'a [u64],
+    /// The samples to display; absent (`None`) samples render as a blank cell styled with
+    /// `absent_value_style` instead of being treated as zero.
+    ///
+    /// Breaking change: this used to be a public `&'a [u64]` field that callers could read or
+    /// assign directly. Representing gaps requires owning `Option<u64>` samples instead of
+    /// borrowing a caller's `u64` slice, so the field is now private; construct it only through
+    /// [`Sparkline::data`].
+    data: Vec<Option<u64>>,
     /// The maximum value to take to compute the maximum bar height (if nothing is specified, the
     /// widget uses the max of the dataset)
     pub max: Option<u64>,
     /// A set of bar symbols used to represent the give data
     pub bar_set: symbols::bar::Set,
+    /// Direction in which the sparkline is drawn
+    pub direction: RenderDirection,
+    /// Style applied to cells whose sample is absent
+    pub absent_value_style: Style,
 }
 
 impl<'a> Sparkline<'a> {
@@ -46,8 +109,8 @@ impl<'a> Sparkline<'a> {
         self
     }
 
-    pub fn data(mut self, data: &'a [u64]) -> Self {
-        self.data = data;
+    pub fn data(mut self, data: impl IntoSparklineData) -> Self {
+        self.data = data.into_sparkline_data();
         self
     }
 
@@ -60,6 +123,16 @@ impl<'a> Sparkline<'a> {
         self.bar_set = bar_set;
         self
     }
+
+    pub fn direction(mut self, direction: RenderDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    pub fn absent_value_style(mut self, style: Style) -> Self {
+        self.absent_value_style = style;
+        self
+    }
 }
 
 impl<'a> Widget for Sparkline<'a> {
@@ -79,32 +152,51 @@ impl<'a> Widget for Sparkline<'a> {
 
         let max = match self.max {
             Some(v) => v,
-            None => *self.data.iter().max().unwrap_or(&1u64),
+            None => self.data.iter().flatten().max().copied().unwrap_or(1),
         };
         let max_index = min(spark_area.width as usize, self.data.len());
-        let mut data = self
-            .data
+        let samples = match self.direction {
+            RenderDirection::LeftToRight => &self.data[..max_index],
+            RenderDirection::RightToLeft => &self.data[self.data.len() - max_index..],
+        };
+        let mut data = samples
             .iter()
-            .take(max_index)
             .map(|e| {
-                if max != 0 {
-                    e * u64::from(spark_area.height) * 8 / max
-                } else {
-                    0
-                }
+                e.map(|v| {
+                    if max != 0 {
+                        v * u64::from(spark_area.height) * 8 / max
+                    } else {
+                        0
+                    }
+                })
             })
-            .collect::<Vec<u64>>();
+            .collect::<SmallVec<[Option<u64>; INLINE_SAMPLES]>>();
         for j in (0..spark_area.height).rev() {
             for (i, d) in data.iter_mut().enumerate() {
-                let symbol = self.bar_set.symbol(*d as usize);
-                buf.get_mut(spark_area.left() + i as u16, spark_area.top() + j)
-                    .set_symbol(symbol)
-                    .set_style(self.style);
-
-                if *d > 8 {
-                    *d -= 8;
-                } else {
-                    *d = 0;
+                let x = match self.direction {
+                    RenderDirection::LeftToRight => spark_area.left() + i as u16,
+                    RenderDirection::RightToLeft => {
+                        spark_area.right() - data.len() as u16 + i as u16
+                    }
+                };
+                match d {
+                    Some(v) => {
+                        let symbol = self.bar_set.symbol(*v as usize);
+                        buf.get_mut(x, spark_area.top() + j)
+                            .set_symbol(symbol)
+                            .set_style(self.style);
+
+                        if *v > 8 {
+                            *v -= 8;
+                        } else {
+                            *v = 0;
+                        }
+                    }
+                    None => {
+                        buf.get_mut(x, spark_area.top() + j)
+                            .set_symbol(self.bar_set.symbol(0))
+                            .set_style(self.absent_value_style);
+                    }
                 }
             }
         }
@@ -130,4 +222,14 @@ mod tests {
         let mut buffer = Buffer::empty(area);
         widget.render(area, &mut buffer);
     }
+
+    #[test]
+    fn it_does_not_panic_if_thereis_less_data_than_width() {
+        let mut widget = Sparkline::default()
+            .data(&[0, 1])
+            .direction(RenderDirection::RightToLeft);
+        let area = Rect::new(0, 0, 3, 1);
+        let mut buffer = Buffer::empty(area);
+        widget.render(area, &mut buffer);
+    }
 }